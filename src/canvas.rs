@@ -0,0 +1,130 @@
+use crate::color::Color;
+
+const MAX_LINE_LENGTH: usize = 70;
+
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Color>,
+}
+
+impl Canvas {
+    /// Creates a new Canvas of the given width and height, with every pixel
+    /// initialized to black
+    ///
+    /// ```
+    /// # use rtc_rust::canvas::Canvas;
+    /// # use rtc_rust::color::Color;
+    /// let c = Canvas::new(10, 20);
+    /// assert_eq!(c.width, 10);
+    /// assert_eq!(c.height, 20);
+    /// for y in 0..20 {
+    ///     for x in 0..10 {
+    ///         assert_eq!(c.pixel_at(x, y), &Color::new(0., 0., 0.));
+    ///     }
+    /// }
+    /// ```
+    pub fn new(width: usize, height: usize) -> Self {
+        let mut pixels = Vec::with_capacity(width * height);
+        for _ in 0..(width * height) {
+            pixels.push(Color::new(0., 0., 0.));
+        }
+        Self { width, height, pixels }
+    }
+
+    /// Writes a Color to the pixel at (x, y)
+    ///
+    /// ```
+    /// # use rtc_rust::canvas::Canvas;
+    /// # use rtc_rust::color::Color;
+    /// let mut c = Canvas::new(10, 20);
+    /// let red = Color::new(1., 0., 0.);
+    /// c.write_pixel(2, 3, red);
+    /// assert_eq!(c.pixel_at(2, 3), &Color::new(1., 0., 0.));
+    /// ```
+    pub fn write_pixel(&mut self, x: usize, y: usize, color: Color) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// Returns a reference to the Color at (x, y)
+    pub fn pixel_at(&self, x: usize, y: usize) -> &Color {
+        &self.pixels[y * self.width + x]
+    }
+
+    /// Serializes the Canvas to a PPM (P3) formatted String
+    ///
+    /// ```
+    /// # use rtc_rust::canvas::Canvas;
+    /// let c = Canvas::new(5, 3);
+    /// let ppm = c.to_ppm();
+    /// assert!(ppm.starts_with("P3\n5 3\n255\n"));
+    /// assert!(ppm.ends_with('\n'));
+    /// ```
+    ///
+    /// Out-of-range channels are clamped to 0-255 and rounded
+    ///
+    /// ```
+    /// # use rtc_rust::canvas::Canvas;
+    /// # use rtc_rust::color::Color;
+    /// let mut c = Canvas::new(1, 1);
+    /// c.write_pixel(0, 0, Color::new(1.5, -0.5, 0.5));
+    /// let ppm = c.to_ppm();
+    /// assert!(ppm.ends_with("255 0 128\n"));
+    /// ```
+    ///
+    /// Long rows are split across multiple lines, never mid-number, so no
+    /// line exceeds 70 characters
+    ///
+    /// ```
+    /// # use rtc_rust::canvas::Canvas;
+    /// # use rtc_rust::color::Color;
+    /// let mut c = Canvas::new(10, 2);
+    /// let color = Color::new(1., 0.8, 0.6);
+    /// for y in 0..2 {
+    ///     for x in 0..10 {
+    ///         c.write_pixel(x, y, color);
+    ///     }
+    /// }
+    /// let ppm = c.to_ppm();
+    /// let lines: Vec<&str> = ppm.lines().collect();
+    /// assert_eq!(lines[3], "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204");
+    /// assert_eq!(lines[4], "153 255 204 153 255 204 153 255 204 153 255 204 153");
+    /// assert!(lines.iter().all(|line| line.len() <= 70));
+    /// ```
+    pub fn to_ppm(&self) -> String {
+        let mut ppm = String::new();
+        ppm.push_str("P3\n");
+        ppm.push_str(&format!("{} {}\n", self.width, self.height));
+        ppm.push_str("255\n");
+
+        for y in 0..self.height {
+            let mut line = String::new();
+            for x in 0..self.width {
+                let pixel = self.pixel_at(x, y);
+                for channel in [pixel.r, pixel.g, pixel.b] {
+                    let scaled = Self::scale_channel(channel);
+                    let token = scaled.to_string();
+
+                    if line.is_empty() {
+                        line.push_str(&token);
+                    } else if line.len() + 1 + token.len() > MAX_LINE_LENGTH {
+                        ppm.push_str(&line);
+                        ppm.push('\n');
+                        line = token;
+                    } else {
+                        line.push(' ');
+                        line.push_str(&token);
+                    }
+                }
+            }
+            ppm.push_str(&line);
+            ppm.push('\n');
+        }
+
+        ppm
+    }
+
+    fn scale_channel(value: f64) -> u8 {
+        (value * 255.).round().clamp(0., 255.) as u8
+    }
+}
@@ -0,0 +1,111 @@
+use crate::tuple::Tuple;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Color {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+impl PartialEq for Color {
+    fn eq(&self, cmp: &Self) -> bool {
+        self.as_tuple() == cmp.as_tuple()
+    }
+}
+
+impl std::ops::Add<Color> for Color {
+    type Output = Self;
+
+    /// Adds two Colors together, returning sum Color
+    ///
+    /// ```
+    /// # use rtc_rust::color::Color;
+    /// let c1 = Color::new(0.9, 0.6, 0.75);
+    /// let c2 = Color::new(0.7, 0.1, 0.25);
+    /// let expected = Color::new(1.6, 0.7, 1.0);
+    /// let actual = c1 + c2;
+    /// assert_eq!(actual, expected);
+    /// ```
+    fn add(self, cmp: Self) -> Self {
+        Self::from_tuple(self.as_tuple() + cmp.as_tuple())
+    }
+}
+
+impl std::ops::Sub<Color> for Color {
+    type Output = Self;
+
+    /// Subtracts one Color from another, returning difference Color
+    ///
+    /// ```
+    /// # use rtc_rust::color::Color;
+    /// let c1 = Color::new(0.9, 0.6, 0.75);
+    /// let c2 = Color::new(0.7, 0.1, 0.25);
+    /// let expected = Color::new(0.2, 0.5, 0.5);
+    /// let actual = c1 - c2;
+    /// assert_eq!(actual, expected);
+    /// ```
+    fn sub(self, cmp: Self) -> Self {
+        Self::from_tuple(self.as_tuple() - cmp.as_tuple())
+    }
+}
+
+impl std::ops::Mul<f64> for Color {
+    type Output = Self;
+
+    /// Multiplies a Color by a float
+    ///
+    /// ```
+    /// # use rtc_rust::color::Color;
+    /// let c = Color::new(0.2, 0.3, 0.4);
+    /// let expected = Color::new(0.4, 0.6, 0.8);
+    /// let actual = c * 2.;
+    /// assert_eq!(actual, expected);
+    /// ```
+    fn mul(self, cmp: f64) -> Self {
+        Self::from_tuple(self.as_tuple() * cmp)
+    }
+}
+
+impl Color {
+    /// Creates a new Color
+    ///
+    /// ```
+    /// # use rtc_rust::color::Color;
+    /// let c = Color::new(-0.5, 0.4, 1.7);
+    /// assert_eq!(c.r, -0.5);
+    /// assert_eq!(c.g, 0.4);
+    /// assert_eq!(c.b, 1.7);
+    /// ```
+    pub fn new(r: f64, g: f64, b: f64) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Returns the Hadamard (Schur) product of two Colors, multiplying
+    /// channels pairwise
+    ///
+    /// ```
+    /// # use rtc_rust::color::Color;
+    /// let c1 = Color::new(1., 0.2, 0.4);
+    /// let c2 = Color::new(0.9, 1., 0.1);
+    /// let expected = Color::new(0.9, 0.2, 0.04);
+    /// let actual = c1.hadamard(&c2);
+    /// assert_eq!(actual, expected);
+    /// ```
+    pub fn hadamard(&self, cmp: &Self) -> Self {
+        Self {
+            r: self.r * cmp.r,
+            g: self.g * cmp.g,
+            b: self.b * cmp.b,
+        }
+    }
+
+    /// Represents this Color's channels as a Tuple, reusing Tuple's
+    /// component-wise arithmetic for Add/Sub/Mul
+    fn as_tuple(&self) -> Tuple {
+        Tuple::new(self.r, self.g, self.b, 0.)
+    }
+
+    fn from_tuple(t: Tuple) -> Self {
+        Self { r: t.x, g: t.y, b: t.z }
+    }
+}
@@ -0,0 +1,18 @@
+pub mod tuple;
+pub mod point;
+pub mod vector;
+pub mod color;
+pub mod canvas;
+pub mod matrix;
+pub mod ray;
+pub mod sphere;
+pub mod light;
+pub mod material;
+
+const EPSILON: f64 = 0.00001;
+
+/// Compares two floats for equality within a small tolerance, since direct
+/// equality on `f64` is unreliable for values produced by arithmetic.
+pub fn approx_eq(a: f64, b: f64) -> bool {
+    (a - b).abs() < EPSILON
+}
@@ -0,0 +1,25 @@
+use crate::color::Color;
+use crate::point::Point;
+
+pub struct PointLight {
+    pub position: Point,
+    pub intensity: Color,
+}
+
+impl PointLight {
+    /// Creates a new PointLight with the given position and intensity
+    ///
+    /// ```
+    /// # use rtc_rust::color::Color;
+    /// # use rtc_rust::light::PointLight;
+    /// # use rtc_rust::point::Point;
+    /// let intensity = Color::new(1., 1., 1.);
+    /// let position = Point::new(0., 0., 0.);
+    /// let light = PointLight::new(position, intensity);
+    /// assert_eq!(light.position, Point::new(0., 0., 0.));
+    /// assert_eq!(light.intensity, Color::new(1., 1., 1.));
+    /// ```
+    pub fn new(position: Point, intensity: Color) -> Self {
+        Self { position, intensity }
+    }
+}
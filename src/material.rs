@@ -0,0 +1,150 @@
+use crate::color::Color;
+use crate::light::PointLight;
+use crate::point::Point;
+use crate::vector::Vector;
+
+pub struct Material {
+    pub color: Color,
+    pub ambient: f64,
+    pub diffuse: f64,
+    pub specular: f64,
+    pub shininess: f64,
+}
+
+impl Material {
+    /// Creates a new Material with the standard default surface properties
+    pub fn new() -> Self {
+        Self {
+            color: Color::new(1., 1., 1.),
+            ambient: 0.1,
+            diffuse: 0.9,
+            specular: 0.9,
+            shininess: 200.,
+        }
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the Phong reflection model for `material` lit by `light` at
+/// `point`, given the eye and surface normal vectors
+///
+/// ```
+/// # use rtc_rust::color::Color;
+/// # use rtc_rust::light::PointLight;
+/// # use rtc_rust::material::{lighting, Material};
+/// # use rtc_rust::point::Point;
+/// # use rtc_rust::vector::Vector;
+/// let m = Material::new();
+/// let position = Point::new(0., 0., 0.);
+/// let eyev = Vector::new(0., 0., -1.);
+/// let normalv = Vector::new(0., 0., -1.);
+/// let light = PointLight::new(Point::new(0., 0., -10.), Color::new(1., 1., 1.));
+/// let result = lighting(&m, &light, &position, &eyev, &normalv);
+/// assert_eq!(result, Color::new(1.9, 1.9, 1.9));
+/// ```
+///
+/// Lighting with the eye between light and surface, offset 45°
+///
+/// ```
+/// # use rtc_rust::color::Color;
+/// # use rtc_rust::light::PointLight;
+/// # use rtc_rust::material::{lighting, Material};
+/// # use rtc_rust::point::Point;
+/// # use rtc_rust::vector::Vector;
+/// let m = Material::new();
+/// let position = Point::new(0., 0., 0.);
+/// let eyev = Vector::new(0., 2.0_f64.sqrt() / 2., -(2.0_f64.sqrt() / 2.));
+/// let normalv = Vector::new(0., 0., -1.);
+/// let light = PointLight::new(Point::new(0., 0., -10.), Color::new(1., 1., 1.));
+/// let result = lighting(&m, &light, &position, &eyev, &normalv);
+/// assert_eq!(result, Color::new(1.0, 1.0, 1.0));
+/// ```
+///
+/// Lighting with the eye opposite the surface, light offset 45°
+///
+/// ```
+/// # use rtc_rust::color::Color;
+/// # use rtc_rust::light::PointLight;
+/// # use rtc_rust::material::{lighting, Material};
+/// # use rtc_rust::point::Point;
+/// # use rtc_rust::vector::Vector;
+/// let m = Material::new();
+/// let position = Point::new(0., 0., 0.);
+/// let eyev = Vector::new(0., 0., -1.);
+/// let normalv = Vector::new(0., 0., -1.);
+/// let light = PointLight::new(Point::new(0., 10., -10.), Color::new(1., 1., 1.));
+/// let result = lighting(&m, &light, &position, &eyev, &normalv);
+/// assert_eq!(result, Color::new(0.7364, 0.7364, 0.7364));
+/// ```
+///
+/// Lighting with the eye in the path of the reflection vector
+///
+/// ```
+/// # use rtc_rust::color::Color;
+/// # use rtc_rust::light::PointLight;
+/// # use rtc_rust::material::{lighting, Material};
+/// # use rtc_rust::point::Point;
+/// # use rtc_rust::vector::Vector;
+/// let m = Material::new();
+/// let position = Point::new(0., 0., 0.);
+/// let eyev = Vector::new(0., -(2.0_f64.sqrt() / 2.), -(2.0_f64.sqrt() / 2.));
+/// let normalv = Vector::new(0., 0., -1.);
+/// let light = PointLight::new(Point::new(0., 10., -10.), Color::new(1., 1., 1.));
+/// let result = lighting(&m, &light, &position, &eyev, &normalv);
+/// assert_eq!(result, Color::new(1.6364, 1.6364, 1.6364));
+/// ```
+///
+/// Lighting with the light behind the surface
+///
+/// ```
+/// # use rtc_rust::color::Color;
+/// # use rtc_rust::light::PointLight;
+/// # use rtc_rust::material::{lighting, Material};
+/// # use rtc_rust::point::Point;
+/// # use rtc_rust::vector::Vector;
+/// let m = Material::new();
+/// let position = Point::new(0., 0., 0.);
+/// let eyev = Vector::new(0., 0., -1.);
+/// let normalv = Vector::new(0., 0., -1.);
+/// let light = PointLight::new(Point::new(0., 0., 10.), Color::new(1., 1., 1.));
+/// let result = lighting(&m, &light, &position, &eyev, &normalv);
+/// assert_eq!(result, Color::new(0.1, 0.1, 0.1));
+/// ```
+pub fn lighting(
+    material: &Material,
+    light: &PointLight,
+    point: &Point,
+    eyev: &Vector,
+    normalv: &Vector,
+) -> Color {
+    let ambient = material.color.hadamard(&light.intensity) * material.ambient;
+
+    let lightv = (light.position - *point).norm();
+
+    let black = Color::new(0., 0., 0.);
+    let light_dot_normal = lightv.dot(normalv);
+
+    let (diffuse, specular) = if light_dot_normal < 0. {
+        (black, black)
+    } else {
+        let diffuse = material.color.hadamard(&light.intensity) * material.diffuse * light_dot_normal;
+
+        let reflectv = (-lightv).reflect(normalv);
+        let reflect_dot_eye = reflectv.dot(eyev);
+
+        let specular = if reflect_dot_eye <= 0. {
+            black
+        } else {
+            light.intensity * material.specular * reflect_dot_eye.powf(material.shininess)
+        };
+
+        (diffuse, specular)
+    };
+
+    ambient + diffuse + specular
+}
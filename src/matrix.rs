@@ -0,0 +1,405 @@
+use crate::point::Point;
+use crate::tuple::Tuple;
+use crate::vector::Vector;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix {
+    data: [[f64; 4]; 4],
+}
+
+impl PartialEq for Matrix {
+    fn eq(&self, cmp: &Self) -> bool {
+        for row in 0..4 {
+            for col in 0..4 {
+                if !super::approx_eq(self.data[row][col], cmp.data[row][col]) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl std::ops::Mul<Matrix> for Matrix {
+    type Output = Self;
+
+    /// Multiplies two 4x4 Matrices together, returning the product Matrix
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// let a = Matrix::new([
+    ///     [1., 2., 3., 4.],
+    ///     [5., 6., 7., 8.],
+    ///     [9., 8., 7., 6.],
+    ///     [5., 4., 3., 2.],
+    /// ]);
+    /// let b = Matrix::new([
+    ///     [-2., 1., 2., 3.],
+    ///     [3., 2., 1., -1.],
+    ///     [4., 3., 6., 5.],
+    ///     [1., 2., 7., 8.],
+    /// ]);
+    /// let expected = Matrix::new([
+    ///     [20., 22., 50., 48.],
+    ///     [44., 54., 114., 108.],
+    ///     [40., 58., 110., 102.],
+    ///     [16., 26., 46., 42.],
+    /// ]);
+    /// assert_eq!(a * b, expected);
+    /// ```
+    fn mul(self, cmp: Self) -> Self {
+        let data = std::array::from_fn(|row| {
+            std::array::from_fn(|col| (0..4).map(|i| self.data[row][i] * cmp.data[i][col]).sum())
+        });
+        Self { data }
+    }
+}
+
+impl std::ops::Mul<Tuple> for Matrix {
+    type Output = Tuple;
+
+    /// Multiplies a Matrix by a Tuple, treating the Tuple as a column vector
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::tuple::Tuple;
+    /// let a = Matrix::new([
+    ///     [1., 2., 3., 4.],
+    ///     [2., 4., 4., 2.],
+    ///     [8., 6., 4., 1.],
+    ///     [0., 0., 0., 1.],
+    /// ]);
+    /// let b = Tuple::new(1., 2., 3., 1.);
+    /// let expected = Tuple::new(18., 24., 33., 1.);
+    /// assert_eq!(a * b, expected);
+    /// ```
+    fn mul(self, cmp: Tuple) -> Tuple {
+        let components = [cmp.x, cmp.y, cmp.z, cmp.w];
+        let result: [f64; 4] =
+            std::array::from_fn(|row| (0..4).map(|i| self.data[row][i] * components[i]).sum());
+        Tuple::new(result[0], result[1], result[2], result[3])
+    }
+}
+
+impl std::ops::Mul<Point> for Matrix {
+    type Output = Point;
+
+    /// Multiplies a Matrix by a Point, applying the transform to its position
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::point::Point;
+    /// let transform = Matrix::translation(5., -3., 2.);
+    /// let p = Point::new(-3., 4., 5.);
+    /// let expected = Point::new(2., 1., 7.);
+    /// assert_eq!(transform * p, expected);
+    /// ```
+    ///
+    /// Chained transformations must be applied in reverse order: `c * b * a`
+    /// applied to a point performs `a`, then `b`, then `c`
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::point::Point;
+    /// let p = Point::new(1., 0., 1.);
+    /// let a = Matrix::rotation_x(std::f64::consts::PI / 2.);
+    /// let b = Matrix::scaling(5., 5., 5.);
+    /// let c = Matrix::translation(10., 5., 7.);
+    ///
+    /// let p2 = a * p;
+    /// assert_eq!(p2, Point::new(1., -1., 0.));
+    ///
+    /// let p3 = b * p2;
+    /// assert_eq!(p3, Point::new(5., -5., 0.));
+    ///
+    /// let p4 = c * p3;
+    /// assert_eq!(p4, Point::new(15., 0., 7.));
+    ///
+    /// let chained = c * b * a;
+    /// assert_eq!(chained * p, p4);
+    /// ```
+    fn mul(self, cmp: Point) -> Point {
+        let result = self * cmp.as_tuple();
+        Point::new(result.x, result.y, result.z)
+    }
+}
+
+impl std::ops::Mul<Vector> for Matrix {
+    type Output = Vector;
+
+    /// Multiplies a Matrix by a Vector, applying the transform to its
+    /// direction (translation has no effect)
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::vector::Vector;
+    /// let transform = Matrix::translation(5., -3., 2.);
+    /// let v = Vector::new(-3., 4., 5.);
+    /// assert_eq!(transform * v, v);
+    /// ```
+    fn mul(self, cmp: Vector) -> Vector {
+        let result = self * cmp.as_tuple();
+        Vector::new(result.x, result.y, result.z)
+    }
+}
+
+impl Matrix {
+    /// Creates a new Matrix from a row-major 4x4 array
+    pub fn new(data: [[f64; 4]; 4]) -> Self {
+        Self { data }
+    }
+
+    /// Returns the element at the given row and column
+    pub fn at(&self, row: usize, col: usize) -> f64 {
+        self.data[row][col]
+    }
+
+    /// Returns the 4x4 identity Matrix
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::tuple::Tuple;
+    /// let a = Tuple::new(1., 2., 3., 4.);
+    /// let expected = Tuple::new(1., 2., 3., 4.);
+    /// assert_eq!(Matrix::identity() * a, expected);
+    /// ```
+    pub fn identity() -> Self {
+        Self::new([
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    /// Returns the transpose of this Matrix
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// let a = Matrix::new([
+    ///     [0., 9., 3., 0.],
+    ///     [9., 8., 0., 8.],
+    ///     [1., 8., 5., 3.],
+    ///     [0., 0., 5., 8.],
+    /// ]);
+    /// let expected = Matrix::new([
+    ///     [0., 9., 1., 0.],
+    ///     [9., 8., 8., 0.],
+    ///     [3., 0., 5., 5.],
+    ///     [0., 8., 3., 8.],
+    /// ]);
+    /// assert_eq!(a.transpose(), expected);
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let data = std::array::from_fn(|row| std::array::from_fn(|col| self.data[col][row]));
+        Self { data }
+    }
+
+    /// Returns a translation Matrix
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::tuple::Tuple;
+    /// let transform = Matrix::translation(5., -3., 2.);
+    /// let p = Tuple::point(-3., 4., 5.);
+    /// let expected = Tuple::point(2., 1., 7.);
+    /// assert_eq!(transform * p, expected);
+    /// ```
+    pub fn translation(x: f64, y: f64, z: f64) -> Self {
+        Self::new([
+            [1., 0., 0., x],
+            [0., 1., 0., y],
+            [0., 0., 1., z],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    /// Returns a scaling Matrix
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::tuple::Tuple;
+    /// let transform = Matrix::scaling(2., 3., 4.);
+    /// let p = Tuple::point(-4., 6., 8.);
+    /// let expected = Tuple::point(-8., 18., 32.);
+    /// assert_eq!(transform * p, expected);
+    /// ```
+    pub fn scaling(x: f64, y: f64, z: f64) -> Self {
+        Self::new([
+            [x, 0., 0., 0.],
+            [0., y, 0., 0.],
+            [0., 0., z, 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    /// Returns a Matrix that rotates around the x axis by `radians`
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::tuple::Tuple;
+    /// let half_quarter = Matrix::rotation_x(std::f64::consts::PI / 4.);
+    /// let p = Tuple::point(0., 1., 0.);
+    /// let expected = Tuple::point(0., 2.0_f64.sqrt() / 2., 2.0_f64.sqrt() / 2.);
+    /// assert_eq!(half_quarter * p, expected);
+    /// ```
+    pub fn rotation_x(radians: f64) -> Self {
+        Self::new([
+            [1., 0., 0., 0.],
+            [0., radians.cos(), -radians.sin(), 0.],
+            [0., radians.sin(), radians.cos(), 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    /// Returns a Matrix that rotates around the y axis by `radians`
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::tuple::Tuple;
+    /// let half_quarter = Matrix::rotation_y(std::f64::consts::PI / 4.);
+    /// let p = Tuple::point(0., 0., 1.);
+    /// let expected = Tuple::point(2.0_f64.sqrt() / 2., 0., 2.0_f64.sqrt() / 2.);
+    /// assert_eq!(half_quarter * p, expected);
+    /// ```
+    pub fn rotation_y(radians: f64) -> Self {
+        Self::new([
+            [radians.cos(), 0., radians.sin(), 0.],
+            [0., 1., 0., 0.],
+            [-radians.sin(), 0., radians.cos(), 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    /// Returns a Matrix that rotates around the z axis by `radians`
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::tuple::Tuple;
+    /// let half_quarter = Matrix::rotation_z(std::f64::consts::PI / 4.);
+    /// let p = Tuple::point(0., 1., 0.);
+    /// let expected = Tuple::point(-(2.0_f64.sqrt() / 2.), 2.0_f64.sqrt() / 2., 0.);
+    /// assert_eq!(half_quarter * p, expected);
+    /// ```
+    pub fn rotation_z(radians: f64) -> Self {
+        Self::new([
+            [radians.cos(), -radians.sin(), 0., 0.],
+            [radians.sin(), radians.cos(), 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    /// Returns a shearing Matrix, moving each component in proportion to the
+    /// other two
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::tuple::Tuple;
+    /// let transform = Matrix::shearing(1., 0., 0., 0., 0., 0.);
+    /// let p = Tuple::point(2., 3., 4.);
+    /// let expected = Tuple::point(5., 3., 4.);
+    /// assert_eq!(transform * p, expected);
+    /// ```
+    pub fn shearing(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Self {
+        Self::new([
+            [1., xy, xz, 0.],
+            [yx, 1., yz, 0.],
+            [zx, zy, 1., 0.],
+            [0., 0., 0., 1.],
+        ])
+    }
+
+    /// Returns the determinant of this Matrix
+    pub fn determinant(&self) -> f64 {
+        determinant(&self.rows())
+    }
+
+    /// Returns the determinant of the 3x3 submatrix with `row` and `col`
+    /// removed
+    pub fn minor(&self, row: usize, col: usize) -> f64 {
+        determinant(&submatrix(&self.rows(), row, col))
+    }
+
+    /// Returns the signed minor at (`row`, `col`), negated when `row + col`
+    /// is odd
+    pub fn cofactor(&self, row: usize, col: usize) -> f64 {
+        let minor = self.minor(row, col);
+        if (row + col).is_multiple_of(2) {
+            minor
+        } else {
+            -minor
+        }
+    }
+
+    /// Returns the inverse of this Matrix, or `None` when it is not
+    /// invertible (determinant approximately zero)
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// let a = Matrix::new([
+    ///     [-5., 2., 6., -8.],
+    ///     [1., -5., 1., 8.],
+    ///     [7., 7., -6., -7.],
+    ///     [1., -3., 7., 4.],
+    /// ]);
+    /// let b = a.inverse().unwrap();
+    /// assert_eq!(a * b, Matrix::identity());
+    /// ```
+    ///
+    /// A Matrix with a zero determinant is not invertible
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// let a = Matrix::new([
+    ///     [0., 0., 0., 0.],
+    ///     [0., 0., 0., 0.],
+    ///     [0., 0., 0., 0.],
+    ///     [0., 0., 0., 0.],
+    /// ]);
+    /// assert!(a.inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if super::approx_eq(det, 0.) {
+            return None;
+        }
+
+        let data = std::array::from_fn(|row| {
+            std::array::from_fn(|col| self.cofactor(col, row) / det)
+        });
+        Some(Self { data })
+    }
+
+    fn rows(&self) -> Vec<Vec<f64>> {
+        self.data.iter().map(|row| row.to_vec()).collect()
+    }
+}
+
+fn submatrix(m: &[Vec<f64>], row: usize, col: usize) -> Vec<Vec<f64>> {
+    m.iter()
+        .enumerate()
+        .filter(|(r, _)| *r != row)
+        .map(|(_, cols)| {
+            cols.iter()
+                .enumerate()
+                .filter(|(c, _)| *c != col)
+                .map(|(_, v)| *v)
+                .collect()
+        })
+        .collect()
+}
+
+fn determinant(m: &[Vec<f64>]) -> f64 {
+    if m.len() == 2 {
+        m[0][0] * m[1][1] - m[0][1] * m[1][0]
+    } else {
+        (0..m.len())
+            .map(|col| {
+                let minor = determinant(&submatrix(m, 0, col));
+                let cofactor = if col % 2 == 0 { minor } else { -minor };
+                m[0][col] * cofactor
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,79 @@
+use crate::tuple::Tuple;
+use crate::vector::Vector;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Point(Tuple);
+
+impl PartialEq for Point {
+    fn eq(&self, cmp: &Self) -> bool {
+        self.0 == cmp.0
+    }
+}
+
+impl std::ops::Sub<Point> for Point {
+    type Output = Vector;
+
+    /// Subtracts one Point from another, returning the Vector between them
+    ///
+    /// ```
+    /// # use rtc_rust::point::Point;
+    /// # use rtc_rust::vector::Vector;
+    /// let p1 = Point::new(3., 2., 1.);
+    /// let p2 = Point::new(5., 6., 7.);
+    /// let expected = Vector::new(-2., -4., -6.);
+    /// assert_eq!(p1 - p2, expected);
+    /// ```
+    fn sub(self, cmp: Self) -> Vector {
+        Vector::new(self.0.x - cmp.0.x, self.0.y - cmp.0.y, self.0.z - cmp.0.z)
+    }
+}
+
+impl std::ops::Add<Vector> for Point {
+    type Output = Point;
+
+    /// Adds a Vector to a Point, returning the translated Point
+    ///
+    /// ```
+    /// # use rtc_rust::point::Point;
+    /// # use rtc_rust::vector::Vector;
+    /// let p = Point::new(3., 2., 1.);
+    /// let v = Vector::new(-2., 3., 1.);
+    /// let expected = Point::new(1., 5., 2.);
+    /// assert_eq!(p + v, expected);
+    /// ```
+    fn add(self, cmp: Vector) -> Point {
+        Point::new(self.0.x + cmp.x(), self.0.y + cmp.y(), self.0.z + cmp.z())
+    }
+}
+
+impl Point {
+    /// Creates a new Point
+    ///
+    /// ```
+    /// # use rtc_rust::point::Point;
+    /// let p = Point::new(4., -4., 3.);
+    /// assert_eq!(p.x(), 4.);
+    /// assert_eq!(p.y(), -4.);
+    /// assert_eq!(p.z(), 3.);
+    /// ```
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self(Tuple::point(x, y, z))
+    }
+
+    pub fn x(&self) -> f64 {
+        self.0.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.0.y
+    }
+
+    pub fn z(&self) -> f64 {
+        self.0.z
+    }
+
+    /// Returns the underlying Tuple, for use by Matrix transforms
+    pub(crate) fn as_tuple(&self) -> Tuple {
+        self.0
+    }
+}
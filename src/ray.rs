@@ -0,0 +1,50 @@
+use crate::matrix::Matrix;
+use crate::point::Point;
+use crate::vector::Vector;
+
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Vector,
+}
+
+impl Ray {
+    pub fn new(origin: Point, direction: Vector) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Returns the point along this Ray at distance `t`
+    ///
+    /// ```
+    /// # use rtc_rust::point::Point;
+    /// # use rtc_rust::ray::Ray;
+    /// # use rtc_rust::vector::Vector;
+    /// let r = Ray::new(Point::new(2., 3., 4.), Vector::new(1., 0., 0.));
+    /// assert_eq!(r.position(0.), Point::new(2., 3., 4.));
+    /// assert_eq!(r.position(1.), Point::new(3., 3., 4.));
+    /// assert_eq!(r.position(-1.), Point::new(1., 3., 4.));
+    /// assert_eq!(r.position(2.5), Point::new(4.5, 3., 4.));
+    /// ```
+    pub fn position(&self, t: f64) -> Point {
+        self.origin + self.direction * t
+    }
+
+    /// Returns a new Ray with its origin and direction transformed by `m`
+    ///
+    /// ```
+    /// # use rtc_rust::matrix::Matrix;
+    /// # use rtc_rust::point::Point;
+    /// # use rtc_rust::ray::Ray;
+    /// # use rtc_rust::vector::Vector;
+    /// let r = Ray::new(Point::new(1., 2., 3.), Vector::new(0., 1., 0.));
+    /// let m = Matrix::translation(3., 4., 5.);
+    /// let r2 = r.transform(&m);
+    /// assert_eq!(r2.origin, Point::new(4., 6., 8.));
+    /// assert_eq!(r2.direction, Vector::new(0., 1., 0.));
+    /// ```
+    pub fn transform(&self, m: &Matrix) -> Self {
+        Self {
+            origin: *m * self.origin,
+            direction: *m * self.direction,
+        }
+    }
+}
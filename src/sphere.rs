@@ -0,0 +1,145 @@
+use crate::matrix::Matrix;
+use crate::point::Point;
+use crate::ray::Ray;
+use crate::vector::Vector;
+
+/// A unit sphere at the origin with an attached transform.
+///
+/// `transform` must be invertible: `intersect` and `normal_at` both invert
+/// it and panic if it is singular (e.g. a scaling by zero on some axis).
+pub struct Sphere {
+    pub transform: Matrix,
+}
+
+impl Sphere {
+    /// Creates a new unit Sphere at the origin with an identity transform
+    pub fn new() -> Self {
+        Self { transform: Matrix::identity() }
+    }
+}
+
+impl Default for Sphere {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the distances along `ray` at which it intersects `sphere`, in
+/// ascending order, or an empty Vec when the ray misses
+///
+/// ```
+/// # use rtc_rust::point::Point;
+/// # use rtc_rust::ray::Ray;
+/// # use rtc_rust::sphere::{intersect, Sphere};
+/// # use rtc_rust::vector::Vector;
+/// let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+/// let s = Sphere::new();
+/// let xs = intersect(&s, &r);
+/// assert_eq!(xs, vec![4., 6.]);
+/// ```
+///
+/// A ray tangent to the sphere hits it at two equal points
+///
+/// ```
+/// # use rtc_rust::point::Point;
+/// # use rtc_rust::ray::Ray;
+/// # use rtc_rust::sphere::{intersect, Sphere};
+/// # use rtc_rust::vector::Vector;
+/// let r = Ray::new(Point::new(0., 1., -5.), Vector::new(0., 0., 1.));
+/// let s = Sphere::new();
+/// let xs = intersect(&s, &r);
+/// assert_eq!(xs, vec![5., 5.]);
+/// ```
+///
+/// A ray missing the sphere returns no intersections
+///
+/// ```
+/// # use rtc_rust::point::Point;
+/// # use rtc_rust::ray::Ray;
+/// # use rtc_rust::sphere::{intersect, Sphere};
+/// # use rtc_rust::vector::Vector;
+/// let r = Ray::new(Point::new(0., 2., -5.), Vector::new(0., 0., 1.));
+/// let s = Sphere::new();
+/// let xs = intersect(&s, &r);
+/// assert!(xs.is_empty());
+/// ```
+///
+/// Intersecting a scaled sphere
+///
+/// ```
+/// # use rtc_rust::matrix::Matrix;
+/// # use rtc_rust::point::Point;
+/// # use rtc_rust::ray::Ray;
+/// # use rtc_rust::sphere::{intersect, Sphere};
+/// # use rtc_rust::vector::Vector;
+/// let r = Ray::new(Point::new(0., 0., -5.), Vector::new(0., 0., 1.));
+/// let mut s = Sphere::new();
+/// s.transform = Matrix::scaling(2., 2., 2.);
+/// let xs = intersect(&s, &r);
+/// assert_eq!(xs, vec![3., 7.]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `sphere.transform` is singular (not invertible).
+pub fn intersect(sphere: &Sphere, ray: &Ray) -> Vec<f64> {
+    let inverse = sphere
+        .transform
+        .inverse()
+        .expect("sphere transform must be invertible");
+    let ray = ray.transform(&inverse);
+
+    let sphere_to_ray = ray.origin - Point::new(0., 0., 0.);
+
+    let a = ray.direction.dot(&ray.direction);
+    let b = 2. * ray.direction.dot(&sphere_to_ray);
+    let c = sphere_to_ray.dot(&sphere_to_ray) - 1.;
+    let discriminant = b * b - 4. * a * c;
+
+    if discriminant < 0. {
+        return Vec::new();
+    }
+
+    let t1 = (-b - discriminant.sqrt()) / (2. * a);
+    let t2 = (-b + discriminant.sqrt()) / (2. * a);
+    vec![t1, t2]
+}
+
+/// Returns the surface normal of `sphere` at `world_point`
+///
+/// ```
+/// # use rtc_rust::point::Point;
+/// # use rtc_rust::sphere::{normal_at, Sphere};
+/// # use rtc_rust::vector::Vector;
+/// let s = Sphere::new();
+/// let n = normal_at(&s, &Point::new(1., 0., 0.));
+/// assert_eq!(n, Vector::new(1., 0., 0.));
+/// ```
+///
+/// The normal on a translated sphere
+///
+/// ```
+/// # use rtc_rust::matrix::Matrix;
+/// # use rtc_rust::point::Point;
+/// # use rtc_rust::sphere::{normal_at, Sphere};
+/// # use rtc_rust::vector::Vector;
+/// let mut s = Sphere::new();
+/// s.transform = Matrix::translation(0., 1., 0.);
+/// let n = normal_at(&s, &Point::new(0., 1.70711, -0.70711));
+/// assert_eq!(n, Vector::new(0., 0.70711, -0.70711));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `sphere.transform` is singular (not invertible).
+pub fn normal_at(sphere: &Sphere, world_point: &Point) -> Vector {
+    let inverse = sphere
+        .transform
+        .inverse()
+        .expect("sphere transform must be invertible");
+
+    let object_point = inverse * *world_point;
+    let object_normal = object_point - Point::new(0., 0., 0.);
+
+    (inverse.transpose() * object_normal).norm()
+}
@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Tuple {
     pub x: f64,
     pub y: f64,
@@ -364,4 +364,31 @@ impl Tuple {
             self.x * cmp.y - self.y * cmp.x,
         )
     }
+
+    /// Reflects this Tuple about the given normal
+    ///
+    /// # Examples
+    ///
+    /// Reflecting a vector approaching at 45°
+    ///
+    /// ```
+    /// # use rtc_rust::tuple::Tuple;
+    /// let v = Tuple::vector(1., -1., 0.);
+    /// let n = Tuple::vector(0., 1., 0.);
+    /// let expected = Tuple::vector(1., 1., 0.);
+    /// assert_eq!(v.reflect(&n), expected);
+    /// ```
+    ///
+    /// Reflecting a vector off a slanted surface
+    ///
+    /// ```
+    /// # use rtc_rust::tuple::Tuple;
+    /// let v = Tuple::vector(0., -1., 0.);
+    /// let n = Tuple::vector(2.0_f64.sqrt() / 2., 2.0_f64.sqrt() / 2., 0.);
+    /// let expected = Tuple::vector(1., 0., 0.);
+    /// assert_eq!(v.reflect(&n), expected);
+    /// ```
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * 2. * self.dot(normal)
+    }
 }
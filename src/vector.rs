@@ -0,0 +1,152 @@
+use crate::tuple::Tuple;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Vector(Tuple);
+
+impl PartialEq for Vector {
+    fn eq(&self, cmp: &Self) -> bool {
+        self.0 == cmp.0
+    }
+}
+
+impl std::ops::Add<Vector> for Vector {
+    type Output = Self;
+
+    /// Adds two Vectors together, returning their sum
+    ///
+    /// ```
+    /// # use rtc_rust::vector::Vector;
+    /// let v1 = Vector::new(3., -2., 5.);
+    /// let v2 = Vector::new(-2., 3., 1.);
+    /// let expected = Vector::new(1., 1., 6.);
+    /// assert_eq!(v1 + v2, expected);
+    /// ```
+    fn add(self, cmp: Self) -> Self {
+        Self(self.0 + cmp.0)
+    }
+}
+
+impl std::ops::Mul<f64> for Vector {
+    type Output = Self;
+
+    /// Multiplies a Vector by a float
+    ///
+    /// ```
+    /// # use rtc_rust::vector::Vector;
+    /// let v = Vector::new(1., -2., 3.);
+    /// let expected = Vector::new(3.5, -7., 10.5);
+    /// assert_eq!(v * 3.5, expected);
+    /// ```
+    fn mul(self, cmp: f64) -> Self {
+        Self(self.0 * cmp)
+    }
+}
+
+impl std::ops::Neg for Vector {
+    type Output = Self;
+
+    /// Returns the negated Vector
+    ///
+    /// ```
+    /// # use rtc_rust::vector::Vector;
+    /// let v = Vector::new(1., -2., 3.);
+    /// let expected = Vector::new(-1., 2., -3.);
+    /// assert_eq!(-v, expected);
+    /// ```
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Vector {
+    /// Creates a new Vector
+    ///
+    /// ```
+    /// # use rtc_rust::vector::Vector;
+    /// let v = Vector::new(4., -4., 3.);
+    /// assert_eq!(v.x(), 4.);
+    /// assert_eq!(v.y(), -4.);
+    /// assert_eq!(v.z(), 3.);
+    /// ```
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self(Tuple::vector(x, y, z))
+    }
+
+    pub fn x(&self) -> f64 {
+        self.0.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.0.y
+    }
+
+    pub fn z(&self) -> f64 {
+        self.0.z
+    }
+
+    /// Returns the magnitude of this Vector
+    ///
+    /// ```
+    /// # use rtc_rust::vector::Vector;
+    /// let v = Vector::new(1., 2., 3.);
+    /// assert_eq!(v.mag(), 14.0_f64.sqrt());
+    /// ```
+    pub fn mag(&self) -> f64 {
+        self.0.mag()
+    }
+
+    /// Returns the unit Vector in the same direction as this Vector
+    ///
+    /// ```
+    /// # use rtc_rust::vector::Vector;
+    /// let v = Vector::new(4., 0., 0.);
+    /// let expected = Vector::new(1., 0., 0.);
+    /// assert_eq!(v.norm(), expected);
+    /// ```
+    pub fn norm(&self) -> Self {
+        Self(self.0.norm())
+    }
+
+    /// Returns the dot product of two Vectors
+    ///
+    /// ```
+    /// # use rtc_rust::vector::Vector;
+    /// let a = Vector::new(1., 2., 3.);
+    /// let b = Vector::new(2., 3., 4.);
+    /// assert_eq!(a.dot(&b), 20.);
+    /// ```
+    pub fn dot(&self, cmp: &Self) -> f64 {
+        self.0.dot(&cmp.0)
+    }
+
+    /// Returns the cross product of two Vectors
+    ///
+    /// ```
+    /// # use rtc_rust::vector::Vector;
+    /// let a = Vector::new(1., 2., 3.);
+    /// let b = Vector::new(2., 3., 4.);
+    /// let expected = Vector::new(-1., 2., -1.);
+    /// assert_eq!(a.cross(&b), expected);
+    /// ```
+    pub fn cross(&self, cmp: &Self) -> Self {
+        Self(self.0.cross(&cmp.0))
+    }
+
+    /// Reflects this Vector about the given normal
+    ///
+    /// ```
+    /// # use rtc_rust::vector::Vector;
+    /// let v = Vector::new(1., -1., 0.);
+    /// let n = Vector::new(0., 1., 0.);
+    /// let expected = Vector::new(1., 1., 0.);
+    /// assert_eq!(v.reflect(&n), expected);
+    /// ```
+    pub fn reflect(&self, normal: &Self) -> Self {
+        Self(self.0.reflect(&normal.0))
+    }
+
+    /// Returns the underlying Tuple, for use by Matrix transforms
+    pub(crate) fn as_tuple(&self) -> Tuple {
+        self.0
+    }
+}